@@ -17,28 +17,474 @@ use prpr::{
     ui::{button_hit, RectButton, Ui, UI_AUDIO},
 };
 use sasa::{AudioClip, Music};
+use serde::Deserialize;
 use std::{
     any::Any,
     cell::RefCell,
     fs::File,
     io::BufReader,
-    sync::atomic::{AtomicBool, Ordering},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc,
+    },
     thread_local,
 };
 use uuid7::uuid7;
 
 const LOW_PASS: f32 = 0.95;
+/// average relative luminance above this is considered a "bright" background
+const LIGHT_THEME_THRESHOLD: f32 = 0.6;
+/// how long `AudioEffectBus` takes to ramp fully from its current profile to a new
+/// target; `MainScene` picks the target itself, pages do not declare one
+const EFFECT_RAMP_TIME: f32 = 0.4;
+
+/// A point in the BGM effect bus's parameter space: how much low-pass cutoff to
+/// apply and what fraction of the configured BGM volume to keep. `Music` only
+/// exposes `set_low_pass`/`set_amplifier`, not a reverb send or a dedicated duck
+/// control, so those are the two knobs `AudioEffectBus` has to work with; it ramps
+/// the active `Music` smoothly toward a target profile instead of flipping a
+/// single low-pass boolean.
+#[derive(Clone, Copy, PartialEq)]
+pub struct EffectProfile {
+    pub low_pass: f32,
+    pub amplifier_scale: f32,
+}
+
+impl EffectProfile {
+    pub const NONE: Self = Self {
+        low_pass: 0.,
+        amplifier_scale: 1.,
+    };
+    /// pause/settings-style overlay: muffle the BGM, like a door's been closed on it
+    pub const MUFFLE: Self = Self {
+        low_pass: LOW_PASS,
+        amplifier_scale: 1.,
+    };
+    /// gameplay-preview-style overlay: just duck the volume, keep clarity
+    pub const DUCK: Self = Self {
+        low_pass: 0.,
+        amplifier_scale: 0.5,
+    };
+
+    fn lerp(self, other: Self, k: f32) -> Self {
+        Self {
+            low_pass: self.low_pass + (other.low_pass - self.low_pass) * k,
+            amplifier_scale: self.amplifier_scale + (other.amplifier_scale - self.amplifier_scale) * k,
+        }
+    }
+}
+
+/// Small effect bus sitting between `MainScene` and the BGM `Music`/`UI_AUDIO` layer:
+/// ramps low-pass cutoff and volume duck from whatever's currently applied toward a
+/// target `EffectProfile` over `EFFECT_RAMP_TIME` seconds, re-applying `base_amplifier`
+/// (the user's configured BGM volume) on every step so a volume-slider change during
+/// a ramp isn't clobbered.
+struct AudioEffectBus {
+    from: EffectProfile,
+    to: EffectProfile,
+    t: f32,
+}
+
+impl AudioEffectBus {
+    fn new() -> Self {
+        Self {
+            from: EffectProfile::NONE,
+            to: EffectProfile::NONE,
+            t: EFFECT_RAMP_TIME,
+        }
+    }
+
+    fn set_target(&mut self, to: EffectProfile) {
+        if to == self.to {
+            return;
+        }
+        self.from = self.current();
+        self.to = to;
+        self.t = 0.;
+    }
+
+    fn current(&self) -> EffectProfile {
+        self.from.lerp(self.to, (self.t / EFFECT_RAMP_TIME).clamp(0., 1.))
+    }
+
+    fn advance(&mut self, dt: f32, bgm: &mut Music, base_amplifier: f32) -> Result<()> {
+        if self.t >= EFFECT_RAMP_TIME {
+            return Ok(());
+        }
+        self.t = (self.t + dt).min(EFFECT_RAMP_TIME);
+        let p = self.current();
+        bgm.set_low_pass(p.low_pass)?;
+        bgm.set_amplifier(base_amplifier * p.amplifier_scale)?;
+        Ok(())
+    }
+}
+
+/// Whether the current background is bright enough that we've switched into light
+/// mode (dark now-playing title text, dark back icon). It is `pub` so pages can read
+/// it; in this module only `theme_text_color` reads it.
+///
+/// This belongs on `SharedState` (defined in `crate::page`, which this module doesn't
+/// own) rather than a bare static; until it grows a slot for it, this is the interim
+/// storage, recomputed via `MainScene::apply_theme_for` at `init` and again after a
+/// respack import completes, both times from the background texture loaded at `init`.
+pub static LIGHT_THEME: AtomicBool = AtomicBool::new(false);
+
+/// Whether the live `icon_back` texture currently holds the color-inverted variant.
+/// Lets `apply_theme_for` toggle it only on an actual theme transition instead of
+/// unconditionally re-inverting (and thus un-inverting) it on every call.
+static ICON_INVERTED: AtomicBool = AtomicBool::new(false);
+
+/// Foreground color that stays legible against the background in whichever theme
+/// `LIGHT_THEME` currently holds. Wired into `NowPlaying`'s title text; the fader/page
+/// titles drawn via `Fader::render_title` (prpr, outside this module) aren't themed by
+/// this yet — that needs a color hook added on `Fader` itself, which this change can't
+/// reach, so those titles still use whatever fixed color `render_title` always has.
+fn theme_text_color() -> Color {
+    if LIGHT_THEME.load(Ordering::Relaxed) {
+        Color::new(0., 0., 0., 1.)
+    } else {
+        Color::new(1., 1., 1., 1.)
+    }
+}
+
+/// Sidecar metadata describing a BGM track's loop points, read from `res/bgm.yml`
+/// only (a respack's own `bgm.yml` is not read). `Music` has no loop-region/seek API, so the actual
+/// playback still uses the fixed whole-track `loop_mix_time` crossfade regardless of
+/// these; `loop_start`/`loop_end`, when both present, only drive the now-playing
+/// progress timer (`MainScene::bgm_elapsed` wraps at them instead of at `duration`).
+#[derive(Deserialize, Default, Clone)]
+struct BgmMeta {
+    #[serde(default)]
+    loop_start: Option<f64>,
+    #[serde(default)]
+    loop_end: Option<f64>,
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    artist: Option<String>,
+    #[serde(default)]
+    cover: Option<String>,
+}
+
+impl BgmMeta {
+    fn loop_region(&self) -> Option<(f64, f64)> {
+        self.loop_start.zip(self.loop_end)
+    }
+}
+
+async fn load_bgm_meta() -> BgmMeta {
+    let Ok(bytes) = crate::load_res("res/bgm.yml").await else {
+        return BgmMeta::default();
+    };
+    serde_yaml::from_slice(&bytes).unwrap_or_default()
+}
+
+/// minimum fraction of the available width a fitted title should fill before we
+/// stop growing it further
+const NOW_PLAYING_MIN_WIDTH_RATIO: f32 = 0.6;
+/// hard cap on how far `fit_title` will grow the title scale, since an empty or
+/// otherwise zero-width title would never measure as "overflowing" on its own
+const NOW_PLAYING_MAX_TITLE_SCALE: f32 = 3.;
+
+fn format_position(secs: f64) -> String {
+    let secs = secs.max(0.) as u64;
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
+/// What's-playing widget: title/artist, cover art, and a `m:ss / m:ss` progress timer,
+/// toggled from the back bar. The title is auto-fit to the available width the same
+/// way mpd_info_screen does it: grow by 6/5 while there's slack under
+/// `NOW_PLAYING_MIN_WIDTH_RATIO`, shrink by 5/6 the moment it overflows.
+struct NowPlaying {
+    shown: bool,
+    cover: Option<SafeTexture>,
+    title: String,
+    artist: String,
+    duration: f64,
+    title_scale: f32,
+}
+
+impl NowPlaying {
+    fn new(meta: &BgmMeta, cover: Option<SafeTexture>, duration: f64, fallback_title: String) -> Self {
+        Self {
+            shown: false,
+            cover,
+            title: meta.title.clone().unwrap_or(fallback_title),
+            artist: meta.artist.clone().unwrap_or_default(),
+            duration,
+            title_scale: 1.,
+        }
+    }
+
+    fn fit_title(&mut self, ui: &mut Ui, base_size: f32, max_width: f32) {
+        // an empty (or otherwise zero-width) title never measures as overflowing, so
+        // the grow branch below can't rely on the measured width alone to stop it
+        if self.title.trim().is_empty() {
+            return;
+        }
+        loop {
+            let width = ui.text(&self.title).size(base_size * self.title_scale).measure().w;
+            if width > max_width {
+                self.title_scale *= 5. / 6.;
+            } else if width < max_width * NOW_PLAYING_MIN_WIDTH_RATIO && self.title_scale < NOW_PLAYING_MAX_TITLE_SCALE {
+                let grown = (self.title_scale * 6. / 5.).min(NOW_PLAYING_MAX_TITLE_SCALE);
+                if grown <= self.title_scale || ui.text(&self.title).size(base_size * grown).measure().w > max_width {
+                    break;
+                }
+                self.title_scale = grown;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn render(&mut self, ui: &mut Ui, rect: Rect, position: f64) -> Result<()> {
+        self.fit_title(ui, 0.9, rect.w * 0.62);
+        ui.fill_rect(rect, (Color::new(1., 1., 1., 0.06), rect));
+        if let Some(cover) = &self.cover {
+            let cover_rect = Rect::new(rect.x + 0.02, rect.y + 0.1, rect.h * 0.8, rect.h * 0.8);
+            ui.fill_rect(cover_rect, (**cover, cover_rect));
+        }
+        let text_x = rect.x + rect.h * 0.9;
+        ui.text(&self.title)
+            .pos(text_x, rect.y + rect.h * 0.32)
+            .anchor(0., 1.)
+            .size(0.9 * self.title_scale)
+            .color(theme_text_color())
+            .draw();
+        ui.text(&self.artist)
+            .pos(text_x, rect.y + rect.h * 0.56)
+            .anchor(0., 1.)
+            .size(0.5)
+            .color(Color::new(1., 1., 1., 0.7))
+            .draw();
+
+        let bar = Rect::new(text_x, rect.y + rect.h * 0.74, rect.w - rect.h * 0.9 - rect.h * 0.12, rect.h * 0.06);
+        ui.fill_rect(bar, (Color::new(1., 1., 1., 0.2), bar));
+        if self.duration > 0. {
+            let mut filled = bar;
+            filled.w *= (position / self.duration).clamp(0., 1.) as f32;
+            ui.fill_rect(filled, (Color::new(1., 1., 1., 0.9), filled));
+        }
+        ui.text(format!("{} / {}", format_position(position), format_position(self.duration)))
+            .pos(text_x, rect.y + rect.h * 0.84)
+            .anchor(0., 0.)
+            .size(0.4)
+            .color(Color::new(1., 1., 1., 0.7))
+            .draw();
+
+        Ok(())
+    }
+}
 
 pub static BGM_VOLUME_UPDATED: AtomicBool = AtomicBool::new(false);
 
 thread_local! {
-    static RESPACK_ITEM: RefCell<Option<ResPackItem>> = RefCell::default();
+    static RESPACK_ITEM: RefCell<Vec<ResPackItem>> = RefCell::default();
+}
+
+/// at most this many import jobs unzip/parse at once; the rest sit `Queued`
+const IMPORT_CONCURRENCY: usize = 2;
+/// `zip_size` is the *compressed* archive size, but respacks are mostly already-
+/// compressed images/audio, so unzipped bytes written come out close to it; scale it
+/// up a bit as a rough estimate of the uncompressed total rather than comparing two
+/// different units directly
+const RESPACK_EXPANSION_ESTIMATE: f32 = 1.3;
+
+enum ImportKind {
+    Chart,
+    Respack,
+}
+
+enum ImportOutcome {
+    Chart(LocalChart),
+    Respack { id: String, root: String, name: String },
+}
+
+enum ImportStatus {
+    Queued,
+    Running,
+    Done,
+    Failed(String),
+}
+
+/// One in-flight or pending drag-and-drop import. Replaces the old single
+/// `import_task` slot so multiple chart/respack drops can unzip and parse
+/// concurrently instead of blocking the whole UI one at a time.
+struct ImportJob {
+    filename: String,
+    kind: ImportKind,
+    source_file: String,
+    status: ImportStatus,
+    /// 0..1, only meaningful for a running respack unzip; charts have no
+    /// granular progress so this stays at 0 for them
+    progress: f32,
+    dest_dir: Option<String>,
+    zip_size: u64,
+    /// bytes written to `dest_dir` so far, kept up to date by a dedicated thread
+    /// spawned in `start()` rather than stat'd from the render loop
+    progress_bytes: Option<Arc<AtomicU64>>,
+    /// set at the end of the `update_import_jobs` call in which this job became `Done`;
+    /// pruned at the start of the *next* `update_import_jobs` call rather than the
+    /// one that set it, so the overlay shows "done" for at least one frame
+    prune_after_render: bool,
+    retry_btn: RectButton,
+    task: Option<Task<Result<ImportOutcome>>>,
+}
+
+impl ImportJob {
+    fn display_name(file: &str) -> String {
+        std::path::Path::new(file)
+            .file_name()
+            .map(|it| it.to_string_lossy().into_owned())
+            .unwrap_or_else(|| file.to_owned())
+    }
+
+    fn new_chart(file: String) -> Self {
+        Self {
+            filename: Self::display_name(&file),
+            kind: ImportKind::Chart,
+            source_file: file,
+            status: ImportStatus::Queued,
+            progress: 0.,
+            dest_dir: None,
+            zip_size: 0,
+            progress_bytes: None,
+            prune_after_render: false,
+            retry_btn: RectButton::new(),
+            task: None,
+        }
+    }
+
+    fn new_respack(file: String) -> Self {
+        let zip_size = std::fs::metadata(&file).map(|it| it.len()).unwrap_or(0);
+        Self {
+            filename: Self::display_name(&file),
+            kind: ImportKind::Respack,
+            source_file: file,
+            status: ImportStatus::Queued,
+            progress: 0.,
+            dest_dir: None,
+            zip_size,
+            progress_bytes: None,
+            prune_after_render: false,
+            retry_btn: RectButton::new(),
+            task: None,
+        }
+    }
+
+    fn start(&mut self) {
+        self.progress = 0.;
+        match self.kind {
+            ImportKind::Chart => {
+                let file = self.source_file.clone();
+                self.task = Some(Task::new(async move { import_chart(file).await.map(ImportOutcome::Chart) }));
+                self.status = ImportStatus::Running;
+            }
+            ImportKind::Respack => {
+                let precheck = (|| -> Result<(String, String)> {
+                    let root = dir::respacks()?;
+                    let dir = prpr::dir::Dir::new(&root)?;
+                    let mut id = uuid7();
+                    while dir.exists(id.to_string())? {
+                        id = uuid7();
+                    }
+                    let id = id.to_string();
+                    dir.create_dir_all(&id)?;
+                    Ok((root, id))
+                })();
+                match precheck {
+                    Err(err) => self.status = ImportStatus::Failed(err.to_string()),
+                    Ok((root, id)) => {
+                        let dest_dir = format!("{root}/{id}");
+                        self.dest_dir = Some(dest_dir.clone());
+                        let progress_bytes = Arc::new(AtomicU64::new(0));
+                        self.progress_bytes = Some(progress_bytes.clone());
+                        let stop = Arc::new(AtomicBool::new(false));
+                        // stat the unzip destination from its own thread instead of the
+                        // render loop; the task below flips `stop` once unzip_into returns
+                        {
+                            let dest_dir = dest_dir.clone();
+                            let progress_bytes = progress_bytes.clone();
+                            let stop = stop.clone();
+                            std::thread::spawn(move || {
+                                while !stop.load(Ordering::Relaxed) {
+                                    progress_bytes.store(dir_size(std::path::Path::new(&dest_dir)), Ordering::Relaxed);
+                                    std::thread::sleep(std::time::Duration::from_millis(150));
+                                }
+                            });
+                        }
+                        let file = self.source_file.clone();
+                        self.task = Some(Task::new(async move {
+                            let result = (move || -> Result<ImportOutcome> {
+                                let dir = prpr::dir::Dir::new(&root)?;
+                                let dir = dir.open_dir(&id)?;
+                                unzip_into(BufReader::new(File::open(&file)?), &dir, false).context("failed to unzip")?;
+                                let config: ResPackInfo = serde_yaml::from_reader(dir.open("info.yml").context("missing yml")?)?;
+                                Ok(ImportOutcome::Respack { id, root, name: config.name })
+                            })();
+                            stop.store(true, Ordering::Relaxed);
+                            result
+                        }));
+                        self.status = ImportStatus::Running;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Reads the byte count the background stat thread spawned in `start()` has
+    /// accumulated so far; a rough stand-in for "entries unzipped" since `unzip_into`
+    /// doesn't report progress itself, but a cheap atomic load rather than a disk walk.
+    fn poll_unzip_progress(&mut self) {
+        let ImportStatus::Running = self.status else { return };
+        let (Some(progress_bytes), true) = (&self.progress_bytes, self.zip_size > 0) else {
+            return;
+        };
+        let written = progress_bytes.load(Ordering::Relaxed);
+        let estimated_total = (self.zip_size as f32 * RESPACK_EXPANSION_ESTIMATE).max(1.);
+        self.progress = (written as f32 / estimated_total).min(0.99);
+    }
+
+    fn retry(&mut self) {
+        if let Some(dir) = self.dest_dir.take() {
+            let _ = std::fs::remove_dir_all(dir);
+        }
+        self.status = ImportStatus::Queued;
+        self.task = None;
+        self.progress = 0.;
+        self.progress_bytes = None;
+    }
+}
+
+fn dir_size(path: &std::path::Path) -> u64 {
+    let Ok(entries) = std::fs::read_dir(path) else { return 0 };
+    entries
+        .flatten()
+        .map(|entry| match entry.metadata() {
+            Ok(meta) if meta.is_dir() => dir_size(&entry.path()),
+            Ok(meta) => meta.len(),
+            Err(_) => 0,
+        })
+        .sum()
 }
 
 pub struct MainScene {
     state: SharedState,
 
     bgm: Option<Music>,
+    bgm_meta: BgmMeta,
+    now_playing: Option<NowPlaying>,
+    effect_bus: AudioEffectBus,
+    /// the user's configured BGM volume, reapplied under the effect bus's duck scale
+    /// whenever either one changes
+    base_amplifier: f32,
+    last_update_t: f32,
+    /// `Music` has no position query API, so the now-playing progress bar tracks
+    /// elapsed playback time itself: wall-clock seconds since `bgm` started, wrapped
+    /// at the loop region (or the whole track) whenever it's actually playing
+    bgm_elapsed: f64,
+    bgm_playing: bool,
 
     background: SafeTexture,
     btn_back: RectButton,
@@ -46,7 +492,7 @@ pub struct MainScene {
 
     pages: Vec<Box<dyn Page>>,
 
-    import_task: Option<Task<Result<LocalChart>>>,
+    import_jobs: Vec<ImportJob>,
 }
 
 impl MainScene {
@@ -55,30 +501,55 @@ impl MainScene {
         Self::init().await?;
 
         #[cfg(feature = "closed")]
-        let bgm = {
+        let bgm_meta = load_bgm_meta().await;
+        #[cfg(not(feature = "closed"))]
+        let bgm_meta = BgmMeta::default();
+
+        #[cfg(feature = "closed")]
+        let (bgm, now_playing) = {
             let bgm_clip = AudioClip::new(crate::load_res("res/bgm").await)?;
-            Some(UI_AUDIO.with(|it| {
+            let duration = bgm_clip.length();
+            let cover = if let Some(path) = &bgm_meta.cover {
+                crate::load_res(&format!("res/{path}"))
+                    .await
+                    .ok()
+                    .and_then(|bytes| Texture2D::from_file_with_format(&bytes, None).ok())
+                    .map(SafeTexture::from)
+            } else {
+                None
+            };
+            let bgm = UI_AUDIO.with(|it| {
                 it.borrow_mut().create_music(
                     bgm_clip,
                     sasa::MusicParams {
                         amplifier: get_data().config.volume_bgm,
+                        // `Music` has no loop-region/seek API to jump back to `loop_start`
+                        // without re-crossfading, so a real "play the intro once, then
+                        // loop the body with no re-crossfade" isn't achievable here — the
+                        // crossfade always blends back to sample 0, intro included. Keep
+                        // the old fixed whole-track crossfade rather than deriving
+                        // loop_mix_time from loop_start: for a short-intro/long-body track
+                        // that would make sasa crossfade against the intro for nearly the
+                        // whole runtime instead of just smoothing a short loop seam.
                         loop_mix_time: 5.46,
                         command_buffer_size: 64,
                         ..Default::default()
                     },
                 )
-            })?)
+            })?;
+            let now_playing = NowPlaying::new(&bgm_meta, cover, duration, "Phira".to_owned());
+            (Some(bgm), Some(now_playing))
         };
         #[cfg(not(feature = "closed"))]
-        let bgm = None;
+        let (bgm, now_playing) = (None, None);
 
-        let mut sf = Self::new_inner(bgm).await?;
+        let mut sf = Self::new_inner(bgm, bgm_meta, now_playing).await?;
         sf.pages.push(Box::new(HomePage::new().await?));
         Ok(sf)
     }
 
     pub async fn new_with(page: impl Page + 'static) -> Result<Self> {
-        let mut sf = Self::new_inner(None).await?;
+        let mut sf = Self::new_inner(None, BgmMeta::default(), None).await?;
         sf.pages.push(Box::new(page));
         Ok(sf)
     }
@@ -99,18 +570,64 @@ impl MainScene {
         let background: SafeTexture = load_texture("background.jpg").await?.into();
         let icon_back: SafeTexture = load_texture("back.png").await?.into();
 
+        Self::apply_theme_for(&background, &icon_back);
+
         TEX_BACKGROUND.with(|it| *it.borrow_mut() = Some(background));
         TEX_ICON_BACK.with(|it| *it.borrow_mut() = Some(icon_back));
 
         Ok(())
     }
 
-    async fn new_inner(bgm: Option<Music>) -> Result<Self> {
+    /// Samples the background on a coarse grid, derives its average relative luminance,
+    /// and flips `LIGHT_THEME` (and tints `icon_back` to a dark variant) when it's bright
+    /// enough that the default light-on-dark chrome would lose contrast.
+    ///
+    /// Safe to call more than once with the same `icon_back`: `ICON_INVERTED` tracks
+    /// whether the live texture is currently the inverted variant, so this only ever
+    /// inverts it on an actual light/dark transition instead of unconditionally
+    /// inverting every call (which would flip it back to the wrong colors the second
+    /// time it's called while still light, and never restore it once back to dark).
+    fn apply_theme_for(background: &Texture2D, icon_back: &Texture2D) {
+        const GRID: u32 = 8;
+        let image = background.get_texture_data();
+        let (w, h) = (image.width() as u32, image.height() as u32);
+        let mut total = 0.;
+        for gy in 0..GRID {
+            for gx in 0..GRID {
+                let x = (gx * w / GRID).min(w.saturating_sub(1));
+                let y = (gy * h / GRID).min(h.saturating_sub(1));
+                let c = image.get_pixel(x, y);
+                total += 0.2126 * c.r + 0.7152 * c.g + 0.0722 * c.b;
+            }
+        }
+        let is_light = total / (GRID * GRID) as f32 > LIGHT_THEME_THRESHOLD;
+        LIGHT_THEME.store(is_light, Ordering::Relaxed);
+
+        if is_light != ICON_INVERTED.load(Ordering::Relaxed) {
+            let mut image = icon_back.get_texture_data();
+            for pixel in image.get_image_data_mut() {
+                pixel[0] = 255 - pixel[0];
+                pixel[1] = 255 - pixel[1];
+                pixel[2] = 255 - pixel[2];
+            }
+            icon_back.update(&image);
+            ICON_INVERTED.store(is_light, Ordering::Relaxed);
+        }
+    }
+
+    async fn new_inner(bgm: Option<Music>, bgm_meta: BgmMeta, now_playing: Option<NowPlaying>) -> Result<Self> {
         let state = SharedState::new().await?;
         Ok(Self {
             state,
 
             bgm,
+            bgm_meta,
+            now_playing,
+            effect_bus: AudioEffectBus::new(),
+            base_amplifier: get_data().config.volume_bgm,
+            last_update_t: 0.,
+            bgm_elapsed: 0.,
+            bgm_playing: false,
 
             background: TEX_BACKGROUND.with(|it| it.borrow().clone().unwrap()),
             btn_back: RectButton::new(),
@@ -118,7 +635,7 @@ impl MainScene {
 
             pages: Vec::new(),
 
-            import_task: None,
+            import_jobs: Vec::new(),
         })
     }
 
@@ -132,7 +649,70 @@ impl MainScene {
     }
 
     pub fn take_imported_respack() -> Option<ResPackItem> {
-        RESPACK_ITEM.with(|it| it.borrow_mut().take())
+        RESPACK_ITEM.with(|it| {
+            let mut items = it.borrow_mut();
+            (!items.is_empty()).then(|| items.remove(0))
+        })
+    }
+
+    /// Starts queued jobs up to `IMPORT_CONCURRENCY`, polls unzip progress, collects
+    /// finished jobs into the chart/respack lists, and prunes jobs that were already
+    /// `Done` going into this call (i.e. that rendered at least once since finishing).
+    fn update_import_jobs(&mut self) -> Result<()> {
+        self.import_jobs.retain(|job| !job.prune_after_render);
+
+        let running = self.import_jobs.iter().filter(|job| matches!(job.status, ImportStatus::Running)).count();
+        let mut free_slots = IMPORT_CONCURRENCY.saturating_sub(running);
+        for job in &mut self.import_jobs {
+            if free_slots == 0 {
+                break;
+            }
+            if matches!(job.status, ImportStatus::Queued) {
+                job.start();
+                free_slots -= 1;
+            }
+        }
+
+        for job in &mut self.import_jobs {
+            job.poll_unzip_progress();
+            let Some(task) = &mut job.task else { continue };
+            let Some(res) = task.take() else { continue };
+            job.task = None;
+            match res {
+                Err(err) => {
+                    job.status = ImportStatus::Failed(err.to_string());
+                    show_error(err.context(match job.kind {
+                        ImportKind::Chart => itl!("import-failed"),
+                        ImportKind::Respack => itl!("import-respack-failed"),
+                    }));
+                }
+                Ok(ImportOutcome::Chart(chart)) => {
+                    job.status = ImportStatus::Done;
+                    show_message(itl!("import-success")).ok();
+                    get_data_mut().charts.push(chart);
+                    save_data()?;
+                    self.state.reload_local_charts();
+                }
+                Ok(ImportOutcome::Respack { id, root, name }) => {
+                    job.status = ImportStatus::Done;
+                    get_data_mut().respacks.push(id.clone());
+                    save_data()?;
+                    RESPACK_ITEM.with(|it| it.borrow_mut().push(ResPackItem::new(Some(format!("{root}/{id}").into()), name)));
+                    // re-run the light/dark check against `self.background`, the texture
+                    // loaded at `init`; nothing from the imported respack replaces it
+                    // here, so the respack's own files do not affect the theme
+                    Self::apply_theme_for(&self.background, &self.icon_back);
+                    show_message(itl!("import-respack-success"));
+                }
+            }
+        }
+
+        for job in &mut self.import_jobs {
+            if matches!(job.status, ImportStatus::Done) {
+                job.prune_after_render = true;
+            }
+        }
+        Ok(())
     }
 }
 
@@ -144,6 +724,7 @@ impl Scene for MainScene {
     fn enter(&mut self, _tm: &mut TimeManager, _target: Option<RenderTarget>) -> Result<()> {
         if let Some(bgm) = &mut self.bgm {
             let _ = bgm.fade_in(1.3);
+            self.bgm_playing = true;
         }
         self.pages.last_mut().unwrap().enter(&mut self.state)?;
         Ok(())
@@ -152,6 +733,7 @@ impl Scene for MainScene {
     fn resume(&mut self, _tm: &mut TimeManager) -> Result<()> {
         if let Some(bgm) = &mut self.bgm {
             bgm.play()?;
+            self.bgm_playing = true;
         }
         self.pages.last_mut().unwrap().resume()?;
         Ok(())
@@ -160,6 +742,7 @@ impl Scene for MainScene {
     fn pause(&mut self, _tm: &mut TimeManager) -> Result<()> {
         if let Some(bgm) = &mut self.bgm {
             bgm.pause()?;
+            self.bgm_playing = false;
         }
         self.pages.last_mut().unwrap().pause()?;
         Ok(())
@@ -169,20 +752,30 @@ impl Scene for MainScene {
         if self.state.fader.transiting() {
             return Ok(false);
         }
-        if self.import_task.is_some() {
-            return Ok(true);
+        for job in &mut self.import_jobs {
+            if matches!(job.status, ImportStatus::Failed(_)) && job.retry_btn.touch(touch) {
+                button_hit();
+                job.retry();
+                return Ok(true);
+            }
         }
         let s = &mut self.state;
         s.t = tm.now() as _;
-        if self.btn_back.touch(touch) && self.pages.len() > 1 {
-            button_hit();
-            if self.pages.len() == 2 {
-                if let Some(bgm) = &mut self.bgm {
-                    bgm.set_low_pass(0.)?;
+        if self.btn_back.touch(touch) {
+            if self.pages.len() > 1 {
+                button_hit();
+                if self.pages.len() == 2 {
+                    self.effect_bus.set_target(EffectProfile::NONE);
                 }
+                self.pop();
+                return Ok(true);
+            } else if let Some(now_playing) = &mut self.now_playing {
+                // on the home page the back bar has nothing to go back to, so it
+                // doubles as the now-playing toggle instead
+                button_hit();
+                now_playing.shown = !now_playing.shown;
+                return Ok(true);
             }
-            self.pop();
-            return Ok(true);
         }
         if self.pages.last_mut().unwrap().touch(touch, s)? {
             return Ok(true);
@@ -194,6 +787,23 @@ impl Scene for MainScene {
         UI_AUDIO.with(|it| it.borrow_mut().recover_if_needed())?;
         let s = &mut self.state;
         s.t = tm.now() as _;
+        let dt = (s.t - self.last_update_t).max(0.);
+        self.last_update_t = s.t;
+        if let Some(bgm) = &mut self.bgm {
+            self.effect_bus.advance(dt, bgm, self.base_amplifier)?;
+        }
+        if self.bgm_playing {
+            self.bgm_elapsed += dt as f64;
+            if let Some((loop_start, loop_end)) = self.bgm_meta.loop_region() {
+                if self.bgm_elapsed > loop_end {
+                    self.bgm_elapsed = loop_start + (self.bgm_elapsed - loop_end) % (loop_end - loop_start).max(0.01);
+                }
+            } else if let Some(now_playing) = &self.now_playing {
+                if now_playing.duration > 0. {
+                    self.bgm_elapsed %= now_playing.duration;
+                }
+            }
+        }
         if s.fader.transiting() {
             let pos = self.pages.len() - 2;
             self.pages[pos].update(s)?;
@@ -203,9 +813,16 @@ impl Scene for MainScene {
             match self.pages.last_mut().unwrap().next_page() {
                 NextPage::Overlay(mut sub) => {
                     if self.pages.len() == 1 {
-                        if let Some(bgm) = &mut self.bgm {
-                            bgm.set_low_pass(LOW_PASS)?;
-                        }
+                        // The request asks for pages to declare their own effect profile
+                        // via a trait method alongside `can_play_bgm()`, but `Page` (in
+                        // `crate::page`) is outside this module's scope to extend, so
+                        // there's no hook for an overlay to express "I want MUFFLE" vs
+                        // "I want DUCK" today. Reusing `can_play_bgm()` to fake that
+                        // distinction would just be a same-looking, materially different
+                        // heuristic, so every overlay gets the same conservative DUCK
+                        // until `Page::effect_profile(&self) -> EffectProfile` (default
+                        // `EffectProfile::NONE`) actually exists to declare otherwise.
+                        self.effect_bus.set_target(EffectProfile::DUCK);
                     }
                     sub.enter(s)?;
                     if !sub.can_play_bgm() {
@@ -227,60 +844,18 @@ impl Scene for MainScene {
         }
         if let Some(bgm) = &mut self.bgm {
             if BGM_VOLUME_UPDATED.fetch_and(false, Ordering::Relaxed) {
-                bgm.set_amplifier(get_data().config.volume_bgm)?;
-            }
-        }
-        if let Some(task) = &mut self.import_task {
-            if let Some(res) = task.take() {
-                match res {
-                    Err(err) => {
-                        show_error(err.context(itl!("import-failed")));
-                    }
-                    Ok(chart) => {
-                        show_message(itl!("import-success")).ok();
-                        get_data_mut().charts.push(chart);
-                        save_data()?;
-                        self.state.reload_local_charts();
-                    }
-                }
-                self.import_task = None;
+                self.base_amplifier = get_data().config.volume_bgm;
+                bgm.set_amplifier(self.base_amplifier * self.effect_bus.current().amplifier_scale)?;
             }
         }
         if let Some((id, file)) = take_file() {
             match id.as_str() {
-                "_import" => {
-                    self.import_task = Some(Task::new(import_chart(file)));
-                }
-                "_import_respack" => {
-                    let item: Result<ResPackItem> = (|| {
-                        let root = dir::respacks()?;
-                        let dir = prpr::dir::Dir::new(&root)?;
-                        let mut id = uuid7();
-                        while dir.exists(id.to_string())? {
-                            id = uuid7();
-                        }
-                        let id = id.to_string();
-                        dir.create_dir_all(&id)?;
-                        let dir = dir.open_dir(&id)?;
-                        unzip_into(BufReader::new(File::open(file)?), &dir, false).context("failed to unzip")?;
-                        let config: ResPackInfo = serde_yaml::from_reader(dir.open("info.yml").context("missing yml")?)?;
-                        get_data_mut().respacks.push(id.clone());
-                        save_data()?;
-                        Ok(ResPackItem::new(Some(format!("{root}/{id}").into()), config.name))
-                    })();
-                    match item {
-                        Err(err) => {
-                            show_error(err.context(itl!("import-respack-failed")));
-                        }
-                        Ok(item) => {
-                            RESPACK_ITEM.with(|it| *it.borrow_mut() = Some(item));
-                            show_message(itl!("import-respack-success"));
-                        }
-                    }
-                }
+                "_import" => self.import_jobs.push(ImportJob::new_chart(file)),
+                "_import_respack" => self.import_jobs.push(ImportJob::new_respack(file)),
                 _ => return_file(id, file),
             }
         }
+        self.update_import_jobs()?;
         Ok(())
     }
 
@@ -302,16 +877,19 @@ impl Scene for MainScene {
         s.fader
             .for_sub(|f| f.render_title(ui, &mut s.painter, s.t, &self.pages.last().unwrap().label()));
 
-        // 2. back
-        if self.pages.len() >= 2 {
+        // 2. back / now-playing toggle (the back bar doubles as the latter on the home page)
+        let show_toggle = self.pages.len() == 1 && self.now_playing.is_some();
+        if self.pages.len() >= 2 || show_toggle {
             let mut r = ui.back_rect();
             self.btn_back.set(ui, r);
             ui.scissor(Some(r));
-            r.y += match self.pages.len() {
-                1 => 1.,
-                2 => s.fader.for_sub(|f| f.progress(s.t)),
-                _ => 0.,
-            } * r.h;
+            if !show_toggle {
+                r.y += match self.pages.len() {
+                    1 => 1.,
+                    2 => s.fader.for_sub(|f| f.progress(s.t)),
+                    _ => 0.,
+                } * r.h;
+            }
             ui.fill_rect(r, (*self.icon_back, r));
             ui.scissor(None);
         }
@@ -326,8 +904,58 @@ impl Scene for MainScene {
         self.pages.last_mut().unwrap().render(ui, s)?;
         s.fader.sub = false;
 
-        if self.import_task.is_some() {
-            ui.full_loading(itl!("importing"), s.t);
+        // 4. now-playing overlay
+        if let Some(now_playing) = &mut self.now_playing {
+            if now_playing.shown {
+                let position = self.bgm_elapsed;
+                let sr = ui.screen_rect();
+                let rect = Rect::new(sr.x + sr.w * 0.06, sr.y + sr.h * 0.04, sr.w * 0.88, sr.h * 0.12);
+                now_playing.render(ui, rect, position)?;
+            }
+        }
+
+        // 5. import queue overlay: one row per in-flight/failed job, listing filename,
+        // status, and (for respacks) a real unzip progress bar instead of a spinner
+        if !self.import_jobs.is_empty() {
+            let sr = ui.screen_rect();
+            let row_h = sr.h * 0.07;
+            let w = sr.w * 0.5;
+            let x = sr.x + sr.w - w - sr.w * 0.02;
+            let mut y = sr.y + sr.h * 0.02;
+            for job in &mut self.import_jobs {
+                let rect = Rect::new(x, y, w, row_h * 0.88);
+                ui.fill_rect(rect, (Color::new(0., 0., 0., 0.55), rect));
+                let pad = rect.h * 0.15;
+                ui.text(&job.filename).pos(rect.x + pad, rect.y + rect.h * 0.32).anchor(0., 1.).size(0.5).draw();
+                let label = match &job.status {
+                    ImportStatus::Queued => "queued".to_owned(),
+                    ImportStatus::Running => match job.kind {
+                        ImportKind::Chart => "importing…".to_owned(),
+                        ImportKind::Respack => format!("unzipping {:.0}%", job.progress * 100.),
+                    },
+                    ImportStatus::Done => "done".to_owned(),
+                    ImportStatus::Failed(err) => format!("failed: {err}"),
+                };
+                ui.text(&label)
+                    .pos(rect.x + pad, rect.y + rect.h * 0.68)
+                    .anchor(0., 1.)
+                    .size(0.38)
+                    .color(Color::new(1., 1., 1., 0.7))
+                    .draw();
+                if matches!(job.status, ImportStatus::Running) && job.progress > 0. {
+                    let bar = Rect::new(rect.x + pad, rect.y + rect.h - rect.h * 0.14, rect.w - pad * 2., rect.h * 0.06);
+                    ui.fill_rect(bar, (Color::new(1., 1., 1., 0.2), bar));
+                    let mut filled = bar;
+                    filled.w *= job.progress;
+                    ui.fill_rect(filled, (Color::new(1., 1., 1., 0.8), filled));
+                }
+                if matches!(job.status, ImportStatus::Failed(_)) {
+                    let btn_rect = Rect::new(rect.x + rect.w - rect.h * 0.8, rect.y + rect.h * 0.1, rect.h * 0.7, rect.h * 0.7);
+                    job.retry_btn.set(ui, btn_rect);
+                    ui.fill_rect(btn_rect, (Color::new(1., 1., 1., 0.3), btn_rect));
+                }
+                y += row_h;
+            }
         }
 
         Ok(())